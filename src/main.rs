@@ -1,21 +1,32 @@
 use anyhow::{Context, Result};
 use clap::Parser;
 use futures_util::StreamExt;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+use tokio::io::unix::AsyncFd;
 use tokio::time::sleep;
 use tracing::{debug, info, warn};
 use tracing_subscriber::EnvFilter;
+use udev::{EventType, MonitorBuilder, MonitorSocket};
 use zbus::fdo::PropertiesProxy;
 use zbus::names::InterfaceName;
-use zbus::zvariant::Value;
+use zbus::zvariant::{OwnedObjectPath, Value};
 use zbus::Connection;
 
 const LOGIND_DEST: &str = "org.freedesktop.login1";
 const LOGIND_PATH: &str = "/org/freedesktop/login1";
 const LOGIND_IFACE: &str = "org.freedesktop.login1.Manager";
+const LOGIND_SESSION_IFACE: &str = "org.freedesktop.login1.Session";
 const BACKLIGHT_ROOT: &str = "/sys/class/backlight";
+const BACKLIGHT_SUBSYSTEM: &str = "backlight";
+const LEDS_ROOT: &str = "/sys/class/leds";
+const LEDS_SUBSYSTEM: &str = "leds";
+const KEYBOARD_LED_MARKER: &str = "kbd_backlight";
 const DEVICE_ERROR_INTERVAL: Duration = Duration::from_secs(30);
+// Framebuffer blanking codes used by the `bl_power` sysfs attribute.
+const FB_BLANK_UNBLANK: u32 = 0;
+const FB_BLANK_POWERDOWN: u32 = 4;
 
 #[derive(Parser, Debug)]
 #[command(name = "lid-backlightd", about = "Dim backlight on lid close via logind")]
@@ -26,32 +37,78 @@ struct Args {
     restore_min: u32,
     #[arg(long)]
     log_level: Option<String>,
+    #[arg(long, value_enum, default_value = "sysfs")]
+    backend: Backend,
+    #[arg(long)]
+    power_off: bool,
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Backend {
+    /// Write brightness through logind's Session.SetBrightness, as the calling user.
+    Logind,
+    /// Write brightness directly to sysfs (requires write access to the backlight device).
+    Sysfs,
 }
 
 struct Config {
     device: Option<String>,
+    backend: Backend,
+    power_off: bool,
+    all: bool,
 }
 
 struct Backlight {
     name: String,
+    /// logind `Session.SetBrightness` subsystem argument for this device (`"backlight"` or
+    /// `"leds"`), set from the sysfs root it was discovered under.
+    subsystem: &'static str,
     brightness_path: PathBuf,
+    bl_power_path: Option<PathBuf>,
     max_brightness: u32,
 }
 
-struct State {
-    device: Option<Backlight>,
+/// Per-device dimming state, tracked separately so each backlight or keyboard LED restores to
+/// its own prior brightness independently of the others.
+struct DeviceState {
     saved_brightness: Option<u32>,
+    powered: bool,
+}
+
+impl Default for DeviceState {
+    fn default() -> Self {
+        Self {
+            saved_brightness: None,
+            powered: true,
+        }
+    }
+}
+
+struct State {
+    devices: Vec<Backlight>,
+    device_state: HashMap<String, DeviceState>,
     restore_min: u32,
     last_device_error: Option<Instant>,
+    session_path: Option<OwnedObjectPath>,
+    session_active: bool,
+    /// The most recently dispatched `LidClosed` state, tracked so a device hotplugged mid-way
+    /// through a closed lid can be synced to it immediately instead of waiting for the next
+    /// physical lid toggle.
+    lid_closed: bool,
 }
 
 impl State {
     fn new(restore_min: u32) -> Self {
         Self {
-            device: None,
-            saved_brightness: None,
+            devices: Vec::new(),
+            device_state: HashMap::new(),
             restore_min,
             last_device_error: None,
+            session_path: None,
+            session_active: true,
+            lid_closed: false,
         }
     }
 
@@ -66,50 +123,129 @@ impl State {
         warn!(error = %err, "Backlight access failed");
     }
 
-    fn ensure_device(&mut self, config: &Config) -> Result<()> {
-        if self.device.is_some() {
+    fn ensure_devices(&mut self, config: &Config) -> Result<()> {
+        if !self.devices.is_empty() {
             return Ok(());
         }
 
-        let device = discover_device(config.device.as_deref())?;
-        info!(device = %device.name, max_brightness = device.max_brightness, "Using backlight device");
-        self.device = Some(device);
+        let devices = discover_devices(config)?;
+        for device in &devices {
+            info!(device = %device.name, max_brightness = device.max_brightness, "Using backlight device");
+        }
+        self.devices = devices;
         Ok(())
     }
 
-    fn handle_lid_change(&mut self, config: &Config, closed: bool) -> Result<()> {
-        if closed {
-            if self.saved_brightness.is_none() {
-                self.on_lid_close(config)
-            } else {
-                Ok(())
+    /// Re-run discovery and add any newly-seen devices to the tracked set, for `--all` setups
+    /// where a hotplugged backlight or keyboard LED should join the existing ones rather than
+    /// waiting for the whole set to go empty first. A device added while the lid is already
+    /// closed is dimmed immediately so it doesn't sit at full brightness until the next toggle.
+    async fn merge_discovered_devices(&mut self, config: &Config, connection: Option<&Connection>) -> Result<()> {
+        let discovered = discover_devices(config)?;
+        let existing: std::collections::HashSet<String> =
+            self.devices.iter().map(|device| device.name.clone()).collect();
+        let mut added = Vec::new();
+        for device in discovered {
+            if !existing.contains(&device.name) {
+                info!(device = %device.name, max_brightness = device.max_brightness, "Using backlight device");
+                added.push(device.name.clone());
+                self.devices.push(device);
             }
-        } else {
-            if self.saved_brightness.is_some() {
-                self.on_lid_open(config)
+        }
+
+        if self.lid_closed {
+            for name in added {
+                if let Err(err) = self.close_device(config, connection, &name).await {
+                    self.handle_device_error(Some(&name), &err);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_lid_change(
+        &mut self,
+        config: &Config,
+        closed: bool,
+        connection: Option<&Connection>,
+    ) -> Result<()> {
+        self.lid_closed = closed;
+        if !self.session_active {
+            debug!(closed, "Lid event ignored, session is not active");
+            return Ok(());
+        }
+
+        self.ensure_devices(config)?;
+        let names: Vec<String> = self.devices.iter().map(|device| device.name.clone()).collect();
+        for name in names {
+            let result = if closed {
+                self.close_device(config, connection, &name).await
             } else {
-                Ok(())
+                self.open_device(config, connection, &name).await
+            };
+            if let Err(err) = result {
+                self.handle_device_error(Some(&name), &err);
             }
         }
+        Ok(())
     }
 
-    fn on_lid_close(&mut self, config: &Config) -> Result<()> {
-        self.ensure_device(config)?;
-        let (device_name, brightness_path) = {
-            let device = self.device.as_ref().context("backlight device missing")?;
-            (device.name.clone(), device.brightness_path.clone())
+    async fn close_device(
+        &mut self,
+        config: &Config,
+        connection: Option<&Connection>,
+        name: &str,
+    ) -> Result<()> {
+        let already_closed = {
+            let device_state = self.device_state.entry(name.to_string()).or_default();
+            device_state.saved_brightness.is_some() || !device_state.powered
         };
-        let cur = read_u32(&brightness_path).context("read brightness")?;
+        if already_closed {
+            return Ok(());
+        }
 
-        if self.saved_brightness.is_none() {
-            self.saved_brightness = Some(cur);
+        let (subsystem, brightness_path, bl_power_path) = {
+            let device = self
+                .devices
+                .iter()
+                .find(|device| device.name == name)
+                .context("backlight device missing")?;
+            (device.subsystem, device.brightness_path.clone(), device.bl_power_path.clone())
+        };
+
+        if config.power_off {
+            if let Some(bl_power_path) = bl_power_path {
+                match write_u32(&bl_power_path, FB_BLANK_POWERDOWN) {
+                    Ok(()) => {
+                        self.device_state.get_mut(name).unwrap().powered = false;
+                        info!(device = %name, "Lid closed, powered off backlight");
+                    }
+                    Err(err) => {
+                        self.handle_device_error(Some(name), &err);
+                        warn!(device = %name, "Lid closed, failed to power off backlight");
+                    }
+                }
+                return Ok(());
+            }
+        }
+
+        let cur = read_u32(&brightness_path).context("read brightness")?;
+        let device_state = self.device_state.get_mut(name).unwrap();
+        if device_state.saved_brightness.is_none() {
+            device_state.saved_brightness = Some(cur);
         }
 
         let mut dimmed = false;
-        if let Err(err) = write_u32(&brightness_path, 0) {
-            self.handle_device_error(&err);
-            if let Err(err) = write_u32(&brightness_path, 1) {
-                self.handle_device_error(&err);
+        if let Err(err) = self
+            .write_brightness(config, connection, name, subsystem, &brightness_path, 0)
+            .await
+        {
+            self.handle_device_error(Some(name), &err);
+            if let Err(err) = self
+                .write_brightness(config, connection, name, subsystem, &brightness_path, 1)
+                .await
+            {
+                self.handle_device_error(Some(name), &err);
             } else {
                 dimmed = true;
             }
@@ -118,56 +254,138 @@ impl State {
         }
 
         if dimmed {
-            info!(device = %device_name, "Lid closed, dimmed backlight");
+            info!(device = %name, "Lid closed, dimmed backlight");
         } else {
-            warn!(device = %device_name, "Lid closed, failed to dim backlight");
+            warn!(device = %name, "Lid closed, failed to dim backlight");
         }
         Ok(())
     }
 
-    fn on_lid_open(&mut self, config: &Config) -> Result<()> {
-        let Some(saved) = self.saved_brightness else {
-            debug!("Lid opened, no saved brightness to restore");
+    async fn open_device(
+        &mut self,
+        config: &Config,
+        connection: Option<&Connection>,
+        name: &str,
+    ) -> Result<()> {
+        let needs_restore = {
+            let device_state = self.device_state.entry(name.to_string()).or_default();
+            device_state.saved_brightness.is_some() || !device_state.powered
+        };
+        if !needs_restore {
+            debug!(device = %name, "Lid opened, nothing to restore");
+            return Ok(());
+        }
+
+        let powered = self.device_state.get(name).is_none_or(|state| state.powered);
+        if !powered {
+            let bl_power_path = {
+                let device = self
+                    .devices
+                    .iter()
+                    .find(|device| device.name == name)
+                    .context("backlight device missing")?;
+                device
+                    .bl_power_path
+                    .clone()
+                    .context("bl_power path missing for powered-off device")?
+            };
+            if let Err(err) = write_u32(&bl_power_path, FB_BLANK_UNBLANK) {
+                self.handle_device_error(Some(name), &err);
+            } else {
+                self.device_state.get_mut(name).unwrap().powered = true;
+                info!(device = %name, "Lid opened, powered on backlight");
+            }
+            return Ok(());
+        }
+
+        let Some(saved) = self.device_state.get(name).and_then(|state| state.saved_brightness) else {
+            debug!(device = %name, "Lid opened, no saved brightness to restore");
             return Ok(());
         };
 
-        self.ensure_device(config)?;
-        let (device_name, brightness_path, max_brightness) = {
-            let device = self.device.as_ref().context("backlight device missing")?;
-            (
-                device.name.clone(),
-                device.brightness_path.clone(),
-                device.max_brightness,
-            )
+        let (subsystem, brightness_path, max_brightness) = {
+            let device = self
+                .devices
+                .iter()
+                .find(|device| device.name == name)
+                .context("backlight device missing")?;
+            (device.subsystem, device.brightness_path.clone(), device.max_brightness)
         };
         let min_restore = self.restore_min.min(max_brightness);
         let restore = saved.clamp(min_restore, max_brightness);
 
-        if let Err(err) = write_u32(&brightness_path, restore) {
-            self.handle_device_error(&err);
+        if let Err(err) = self
+            .write_brightness(config, connection, name, subsystem, &brightness_path, restore)
+            .await
+        {
+            self.handle_device_error(Some(name), &err);
         } else {
-            info!(device = %device_name, restore, "Lid opened, restored backlight");
-            self.saved_brightness = None;
+            info!(device = %name, restore, "Lid opened, restored backlight");
+            self.device_state.get_mut(name).unwrap().saved_brightness = None;
         }
 
         Ok(())
     }
 
-    fn restore_on_exit(&mut self, config: &Config) -> Result<()> {
-        if self.saved_brightness.is_none() {
-            return Ok(());
+    async fn restore_on_exit(&mut self, config: &Config, connection: Option<&Connection>) -> Result<()> {
+        let names: Vec<String> = self.devices.iter().map(|device| device.name.clone()).collect();
+        for name in names {
+            if let Err(err) = self.open_device(config, connection, &name).await {
+                self.handle_device_error(Some(&name), &err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a brightness value through the configured backend, keeping the filesystem as the
+    /// source of truth for current/max brightness regardless of which backend does the write.
+    async fn write_brightness(
+        &mut self,
+        config: &Config,
+        connection: Option<&Connection>,
+        device_name: &str,
+        subsystem: &str,
+        brightness_path: &Path,
+        value: u32,
+    ) -> Result<()> {
+        match config.backend {
+            Backend::Sysfs => write_u32(brightness_path, value),
+            Backend::Logind => {
+                let connection = connection.context("logind backend requires an active bus connection")?;
+                let session_path = self.ensure_session(connection).await?;
+                set_session_brightness(connection, &session_path, subsystem, device_name, value).await
+            }
         }
-        self.on_lid_open(config)
     }
 
-    fn handle_device_error(&mut self, err: &anyhow::Error) {
+    async fn ensure_session(&mut self, connection: &Connection) -> Result<OwnedObjectPath> {
+        if let Some(path) = &self.session_path {
+            return Ok(path.clone());
+        }
+        let path = resolve_session_path(connection)
+            .await
+            .context("resolve logind session")?;
+        self.session_path = Some(path.clone());
+        Ok(path)
+    }
+
+    fn handle_device_error(&mut self, device_name: Option<&str>, err: &anyhow::Error) {
         let not_found = err.chain().any(|cause| {
             cause
                 .downcast_ref::<std::io::Error>()
                 .is_some_and(|io_err| io_err.kind() == std::io::ErrorKind::NotFound)
         });
         if not_found {
-            self.device = None;
+            match device_name {
+                Some(name) => {
+                    self.devices.retain(|device| device.name != name);
+                    self.device_state.remove(name);
+                }
+                None => {
+                    self.devices.clear();
+                    self.device_state.clear();
+                }
+            }
         }
         self.log_device_error(err);
     }
@@ -182,10 +400,15 @@ async fn main() -> Result<()> {
     };
     tracing_subscriber::fmt().with_env_filter(filter).init();
 
-    let config = Config { device: args.device };
+    let config = Config {
+        device: args.device,
+        backend: args.backend,
+        power_off: args.power_off,
+        all: args.all,
+    };
     let mut state = State::new(args.restore_min);
-    if let Err(err) = state.ensure_device(&config) {
-        state.handle_device_error(&err);
+    if let Err(err) = state.ensure_devices(&config) {
+        state.handle_device_error(None, &err);
     }
 
     let shutdown = shutdown_signal();
@@ -200,7 +423,20 @@ async fn main() -> Result<()> {
         }
     }
 
-    if let Err(err) = state.restore_on_exit(&config) {
+    let restore_connection = match config.backend {
+        Backend::Logind => match Connection::system().await {
+            Ok(conn) => Some(conn),
+            Err(err) => {
+                warn!(error = %err, "Failed to connect to system bus for shutdown restore");
+                None
+            }
+        },
+        Backend::Sysfs => None,
+    };
+    if let Err(err) = state
+        .restore_on_exit(&config, restore_connection.as_ref())
+        .await
+    {
         warn!(error = %err, "Failed to restore brightness on shutdown");
     }
 
@@ -209,6 +445,7 @@ async fn main() -> Result<()> {
 
 async fn run_loop(config: &Config, state: &mut State) -> Result<()> {
     let mut backoff = Backoff::new(Duration::from_millis(250), Duration::from_secs(5));
+    let udev_monitor = open_udev_monitor().context("open udev monitor")?;
 
     loop {
         let connection = match Connection::system().await {
@@ -247,7 +484,7 @@ async fn run_loop(config: &Config, state: &mut State) -> Result<()> {
             }
         };
 
-        if let Err(err) = process_connection(config, state, &proxy).await {
+        if let Err(err) = process_connection(config, state, &proxy, &udev_monitor, &connection).await {
             warn!(error = %err, "DBus connection error");
         }
 
@@ -256,14 +493,58 @@ async fn run_loop(config: &Config, state: &mut State) -> Result<()> {
     }
 }
 
-async fn process_connection(config: &Config, state: &mut State, proxy: &PropertiesProxy<'_>) -> Result<()> {
-    let iface = InterfaceName::from_static_str_unchecked(LOGIND_IFACE);
-    match proxy.get(iface, "LidClosed").await {
+async fn process_connection(
+    config: &Config,
+    state: &mut State,
+    proxy: &PropertiesProxy<'_>,
+    udev_monitor: &AsyncFd<MonitorSocket>,
+    connection: &Connection,
+) -> Result<()> {
+    let session_proxy = match state.ensure_session(connection).await {
+        Ok(session_path) => match build_session_properties_proxy(connection, &session_path).await {
+            Ok(session_proxy) => {
+                match session_proxy
+                    .get(InterfaceName::from_static_str_unchecked(LOGIND_SESSION_IFACE), "Active")
+                    .await
+                {
+                    Ok(value) => match bool::try_from(&value) {
+                        Ok(active) => {
+                            debug!(active, "Initial session active state");
+                            state.session_active = active;
+                        }
+                        Err(err) => warn!(error = %err, "Invalid Active value"),
+                    },
+                    Err(err) => warn!(error = %err, "Failed to read initial session Active state"),
+                }
+                Some(session_proxy)
+            }
+            Err(err) => {
+                warn!(error = %err, "Failed to build logind session properties proxy");
+                None
+            }
+        },
+        Err(err) => {
+            warn!(error = %err, "Failed to resolve logind session, ignoring session Active state");
+            None
+        }
+    };
+    let mut session_stream = match &session_proxy {
+        Some(session_proxy) => Some(session_proxy.receive_properties_changed().await?),
+        None => None,
+    };
+
+    // Read after the session Active state is established, so a cold start on an inactive
+    // session (VT-switched-away at boot, non-focused seat) doesn't bypass the guard in
+    // `handle_lid_change` by racing ahead of `state.session_active`.
+    match proxy
+        .get(InterfaceName::from_static_str_unchecked(LOGIND_IFACE), "LidClosed")
+        .await
+    {
         Ok(value) => match bool::try_from(&value) {
             Ok(closed) => {
                 debug!(closed, "Initial lid state");
-                if let Err(err) = state.handle_lid_change(config, closed) {
-                    state.handle_device_error(&err);
+                if let Err(err) = state.handle_lid_change(config, closed, Some(connection)).await {
+                    state.handle_device_error(None, &err);
                 }
             }
             Err(err) => {
@@ -275,36 +556,205 @@ async fn process_connection(config: &Config, state: &mut State, proxy: &Properti
         }
     }
 
+    let sleep_proxy = match zbus::Proxy::new(connection, LOGIND_DEST, LOGIND_PATH, LOGIND_IFACE).await {
+        Ok(sleep_proxy) => Some(sleep_proxy),
+        Err(err) => {
+            warn!(error = %err, "Failed to build logind manager proxy for PrepareForSleep");
+            None
+        }
+    };
+    let mut sleep_stream = match &sleep_proxy {
+        Some(sleep_proxy) => Some(sleep_proxy.receive_signal("PrepareForSleep").await?),
+        None => None,
+    };
+
     let mut stream = proxy.receive_properties_changed().await?;
-    while let Some(signal) = stream.next().await {
-        let args = match signal.args() {
-            Ok(args) => args,
-            Err(err) => {
-                warn!(error = %err, "Failed to decode PropertiesChanged signal");
-                continue;
+    loop {
+        tokio::select! {
+            signal = stream.next() => {
+                let Some(signal) = signal else {
+                    return Err(anyhow::anyhow!("properties stream ended"));
+                };
+                let args = match signal.args() {
+                    Ok(args) => args,
+                    Err(err) => {
+                        warn!(error = %err, "Failed to decode PropertiesChanged signal");
+                        continue;
+                    }
+                };
+                if args.interface_name() != LOGIND_IFACE {
+                    continue;
+                }
+                let changed = args.changed_properties();
+                let Some(value) = changed.get("LidClosed") else {
+                    continue;
+                };
+                let closed = match <&Value as TryInto<bool>>::try_into(value) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        warn!(error = %err, "Invalid LidClosed value");
+                        continue;
+                    }
+                };
+
+                if let Err(err) = state.handle_lid_change(config, closed, Some(connection)).await {
+                    state.handle_device_error(None, &err);
+                }
+            }
+            ready = udev_monitor.readable() => {
+                let mut guard = ready.context("udev monitor fd error")?;
+                handle_udev_events(udev_monitor, config, state, Some(connection)).await;
+                guard.clear_ready();
+            }
+            signal = next_from(&mut session_stream) => {
+                let Some(signal) = signal else {
+                    continue;
+                };
+                let args = match signal.args() {
+                    Ok(args) => args,
+                    Err(err) => {
+                        warn!(error = %err, "Failed to decode session PropertiesChanged signal");
+                        continue;
+                    }
+                };
+                if args.interface_name() != LOGIND_SESSION_IFACE {
+                    continue;
+                }
+                let changed = args.changed_properties();
+                let Some(value) = changed.get("Active") else {
+                    continue;
+                };
+                match <&Value as TryInto<bool>>::try_into(value) {
+                    Ok(active) => {
+                        debug!(active, "Session active state changed");
+                        state.session_active = active;
+                        if active {
+                            debug!("Session regained focus, re-applying lid state");
+                            resync_lid_state(config, state, proxy, connection).await;
+                        }
+                    }
+                    Err(err) => warn!(error = %err, "Invalid Active value"),
+                }
+            }
+            msg = next_from(&mut sleep_stream) => {
+                let Some(msg) = msg else {
+                    continue;
+                };
+                match msg.body().deserialize::<bool>() {
+                    Ok(true) => {
+                        debug!("Preparing for sleep, restoring backlight");
+                        if let Err(err) = state.restore_on_exit(config, Some(connection)).await {
+                            warn!(error = %err, "Failed to restore brightness before suspend");
+                        }
+                    }
+                    Ok(false) => {
+                        debug!("Resumed from sleep, re-applying lid state");
+                        resync_lid_state(config, state, proxy, connection).await;
+                    }
+                    Err(err) => warn!(error = %err, "Invalid PrepareForSleep value"),
+                }
             }
-        };
-        if args.interface_name() != LOGIND_IFACE {
-            continue;
         }
-        let changed = args.changed_properties();
-        let Some(value) = changed.get("LidClosed") else {
-            continue;
-        };
-        let closed = match <&Value as TryInto<bool>>::try_into(value) {
-            Ok(value) => value,
-            Err(err) => {
-                warn!(error = %err, "Invalid LidClosed value");
-                continue;
+    }
+}
+
+/// Await the next item from an optional stream, never resolving if the stream is absent so it
+/// can be used as an always-valid `tokio::select!` branch.
+async fn next_from<S>(stream: &mut Option<S>) -> Option<S::Item>
+where
+    S: futures_util::Stream + Unpin,
+{
+    match stream {
+        Some(stream) => stream.next().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Re-read the current `LidClosed` state and re-apply it, used whenever something that was
+/// gating `handle_lid_change` (suspend, an inactive session) clears and may have left a stale
+/// lid state unapplied.
+async fn resync_lid_state(
+    config: &Config,
+    state: &mut State,
+    proxy: &PropertiesProxy<'_>,
+    connection: &Connection,
+) {
+    match proxy
+        .get(InterfaceName::from_static_str_unchecked(LOGIND_IFACE), "LidClosed")
+        .await
+    {
+        Ok(value) => match bool::try_from(&value) {
+            Ok(closed) => {
+                if let Err(err) = state.handle_lid_change(config, closed, Some(connection)).await {
+                    state.handle_device_error(None, &err);
+                }
             }
-        };
+            Err(err) => warn!(error = %err, "Invalid LidClosed value"),
+        },
+        Err(err) => warn!(error = %err, "Failed to read lid state"),
+    }
+}
+
+async fn build_session_properties_proxy(
+    connection: &Connection,
+    session_path: &OwnedObjectPath,
+) -> Result<PropertiesProxy<'static>> {
+    let builder = PropertiesProxy::builder(connection)
+        .destination(LOGIND_DEST)
+        .and_then(|builder| builder.path(session_path.clone()))
+        .context("build logind session properties proxy")?;
+    builder
+        .build()
+        .await
+        .context("build logind session properties proxy")
+}
 
-        if let Err(err) = state.handle_lid_change(config, closed) {
-            state.handle_device_error(&err);
+fn open_udev_monitor() -> Result<AsyncFd<MonitorSocket>> {
+    let socket = MonitorBuilder::new()
+        .context("create udev monitor")?
+        .match_subsystem(BACKLIGHT_SUBSYSTEM)
+        .context("filter udev monitor to backlight subsystem")?
+        .match_subsystem(LEDS_SUBSYSTEM)
+        .context("filter udev monitor to leds subsystem")?
+        .listen()
+        .context("listen on udev monitor socket")?;
+    AsyncFd::new(socket).context("wrap udev monitor fd for async polling")
+}
+
+async fn handle_udev_events(
+    udev_monitor: &AsyncFd<MonitorSocket>,
+    config: &Config,
+    state: &mut State,
+    connection: Option<&Connection>,
+) {
+    for event in udev_monitor.get_ref().iter() {
+        match event.event_type() {
+            EventType::Add | EventType::Change => {
+                debug!(action = ?event.event_type(), "Backlight device appeared");
+                let result = if config.all {
+                    state.merge_discovered_devices(config, connection).await
+                } else if state.devices.is_empty() {
+                    state.ensure_devices(config)
+                } else {
+                    Ok(())
+                };
+                if let Err(err) = result {
+                    state.handle_device_error(None, &err);
+                }
+            }
+            EventType::Remove => {
+                let Some(name) = event.sysname().to_str() else {
+                    continue;
+                };
+                if state.devices.iter().any(|device| device.name == name) {
+                    info!(device = name, "Backlight device removed");
+                    state.devices.retain(|device| device.name != name);
+                    state.device_state.remove(name);
+                }
+            }
+            _ => {}
         }
     }
-
-    Err(anyhow::anyhow!("properties stream ended"))
 }
 
 async fn shutdown_signal() {
@@ -325,8 +775,32 @@ async fn shutdown_signal() {
     }
 }
 
+fn discover_devices(config: &Config) -> Result<Vec<Backlight>> {
+    if !config.all {
+        return Ok(vec![discover_device(config.device.as_deref())?]);
+    }
+
+    let mut devices = Vec::new();
+    for name in list_device_names(BACKLIGHT_ROOT).context("list backlight devices")? {
+        devices.push(Backlight::new(BACKLIGHT_ROOT, name)?);
+    }
+    for name in list_device_names(LEDS_ROOT).context("list LED devices")? {
+        if name.contains(KEYBOARD_LED_MARKER) {
+            devices.push(Backlight::new(LEDS_ROOT, name)?);
+        }
+    }
+    if devices.is_empty() {
+        anyhow::bail!(
+            "no backlight devices in {} or keyboard LEDs in {}",
+            BACKLIGHT_ROOT,
+            LEDS_ROOT
+        );
+    }
+    Ok(devices)
+}
+
 fn discover_device(device_override: Option<&str>) -> Result<Backlight> {
-    let mut devices = list_backlight_devices().context("list backlight devices")?;
+    let mut devices = list_device_names(BACKLIGHT_ROOT).context("list backlight devices")?;
     if devices.is_empty() {
         anyhow::bail!("no backlight devices found in {}", BACKLIGHT_ROOT);
     }
@@ -343,37 +817,84 @@ fn discover_device(device_override: Option<&str>) -> Result<Backlight> {
         devices[0].clone()
     };
 
-    Backlight::new(chosen)
+    Backlight::new(BACKLIGHT_ROOT, chosen)
 }
 
-fn list_backlight_devices() -> Result<Vec<String>> {
-    let mut devices = Vec::new();
-    for entry in std::fs::read_dir(BACKLIGHT_ROOT).context("read backlight directory")? {
-        let entry = entry.context("read backlight entry")?;
+fn list_device_names(root: &str) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(root).with_context(|| format!("read {} directory", root))? {
+        let entry = entry.context("read device entry")?;
         let name = entry.file_name();
         let name = name.to_string_lossy();
         if !name.is_empty() {
-            devices.push(name.to_string());
+            names.push(name.to_string());
         }
     }
-    Ok(devices)
+    Ok(names)
 }
 
 impl Backlight {
-    fn new(name: String) -> Result<Self> {
-        let base = PathBuf::from(BACKLIGHT_ROOT).join(&name);
+    fn new(root: &str, name: String) -> Result<Self> {
+        let subsystem = if root == LEDS_ROOT {
+            LEDS_SUBSYSTEM
+        } else {
+            BACKLIGHT_SUBSYSTEM
+        };
+        let base = PathBuf::from(root).join(&name);
         let brightness_path = base.join("brightness");
         let max_brightness_path = base.join("max_brightness");
         let max_brightness = read_u32(&max_brightness_path).context("read max_brightness")?;
+        let bl_power_path = base.join("bl_power");
+        let bl_power_path = bl_power_path.exists().then_some(bl_power_path);
 
         Ok(Self {
             name,
+            subsystem,
             brightness_path,
+            bl_power_path,
             max_brightness,
         })
     }
 }
 
+async fn resolve_session_path(connection: &Connection) -> Result<OwnedObjectPath> {
+    let pid = std::process::id();
+    let reply = connection
+        .call_method(
+            Some(LOGIND_DEST),
+            LOGIND_PATH,
+            Some(LOGIND_IFACE),
+            "GetSessionByPID",
+            &(pid,),
+        )
+        .await
+        .context("call GetSessionByPID")?;
+    reply
+        .body()
+        .deserialize::<OwnedObjectPath>()
+        .context("decode session object path")
+}
+
+async fn set_session_brightness(
+    connection: &Connection,
+    session_path: &OwnedObjectPath,
+    subsystem: &str,
+    device_name: &str,
+    value: u32,
+) -> Result<()> {
+    connection
+        .call_method(
+            Some(LOGIND_DEST),
+            session_path,
+            Some(LOGIND_SESSION_IFACE),
+            "SetBrightness",
+            &(subsystem, device_name, value),
+        )
+        .await
+        .context("call Session.SetBrightness")?;
+    Ok(())
+}
+
 fn read_u32(path: &Path) -> Result<u32> {
     let contents = std::fs::read_to_string(path)
         .with_context(|| format!("read {}", path.display()))?;